@@ -11,6 +11,51 @@ pub trait TBIPointer: Sized {
     /// Returns the value (if any) stored in the top byte of the pointer.
     #[unstable(feature = "stdarch_aarch64_tbi", issue = "none")]
     fn top_byte(&self) -> u8;
+
+    /// Returns a copy of this pointer with the top byte cleared, preserving provenance through [`with_top_byte`](TBIPointer::with_top_byte) (which itself goes through `with_addr`), mirroring how `get_bytes_strip_provenance`-style helpers separate a pointer's address bits from its provenance rather than exposing them as an integer.
+    ///
+    /// Because TBI and MTE mean several bit-distinct pointers can alias the same allocation, comparing tagged pointers directly with `==` or `Ord` is misleading; use this to get a canonical pointer before comparing, or before handing the pointer to FFI or allocator code that would reject the tag bits, or to provenance-aware tooling like Miri that distinguishes a pointer's address from its allocation.
+    ///
+    /// This only clears the top byte (bits 56..=63), so it does *not* canonicalize a [`PACPointer::sign`]ed pointer: a PAC signature also occupies lower non-address bits (e.g. 54:48, when TBI is enabled) that this method leaves untouched. Use [`PACPointer::strip`] to canonicalize a signed pointer instead.
+    #[unstable(feature = "stdarch_aarch64_tbi", issue = "none")]
+    fn canonical(&self) -> Self
+    where
+        Self: Copy,
+    {
+        (*self).with_top_byte(0)
+    }
+
+    /// Returns whether `self` and `other` are tagged (or untagged) pointers into the same object, i.e. whether their [`canonical`](TBIPointer::canonical) addresses are equal. Like `canonical`, this only accounts for TBI/MTE tag bits, not a PAC signature; see [`PACPointer::strip`] for that.
+    #[unstable(feature = "stdarch_aarch64_tbi", issue = "none")]
+    fn same_object(&self, other: &Self) -> bool
+    where
+        Self: Copy + PartialEq,
+    {
+        self.canonical() == other.canonical()
+    }
+}
+
+#[cfg(feature = "std_detect")]
+use std_detect::is_aarch64_feature_detected;
+
+// There is deliberately no `tbi_supported()`: unlike MTE and PAuth, TBI has no HWCAP bit
+// or `ID_AA64*` field of its own for `is_aarch64_feature_detected!` to query. `with_top_byte`
+// remains unconditional, as it always was; it is on the caller to know their target's ABI
+// honors TBI (e.g. via AArch64 Linux's `PR_SET_TAGGED_ADDR_CTRL`, which is a process-wide
+// opt-in, not a CPU feature).
+
+/// Returns whether the current CPU supports the Memory Tagging Extension (FEAT_MTE), as used by [`MTEPointer`]'s instruction-emitting methods.
+#[unstable(feature = "stdarch_aarch64_mte", issue = "none")]
+#[cfg(feature = "std_detect")]
+pub fn mte_supported() -> bool {
+    is_aarch64_feature_detected!("mte")
+}
+
+/// Returns whether the current CPU supports Pointer Authentication (FEAT_PAuth), as used by [`PACPointer`].
+#[unstable(feature = "stdarch_aarch64_pac", issue = "none")]
+#[cfg(feature = "std_detect")]
+pub fn paca_supported() -> bool {
+    is_aarch64_feature_detected!("paca")
 }
 
 macro_rules! tbi_ptr_impl {
@@ -48,6 +93,200 @@ impl<T> TBIPointer for *mut [T] {
     tbi_ptr_impl!();
 }
 
+/// The bits of the top byte (56..=63) that hold the logical MTE tag (56..=59); the remaining bits (60..=63) are left for TBI-style data and must not be touched here.
+const MTE_TAG_MASK: usize = 0x0f00_0000_0000_0000;
+
+/// Provides additional methods on pointers to manage the allocation tag carried in bits 56 to 59, as per AArch64's Memory Tagging Extension (MTE) feature.
+///
+/// MTE only defines the low nibble of the top byte (56..=59) as the logical tag; the high nibble (60..=63) is left untouched by these methods, so it remains free for TBI-style data as set by [`TBIPointer::with_top_byte`].
+#[unstable(feature = "stdarch_aarch64_mte", issue = "none")]
+pub trait MTEPointer: Sized {
+    /// Returns a new pointer with a fresh, pseudo-randomly generated tag in bits 56 to 59, as per the `IRG` instruction. The data held in bits 60 to 63 is left unchanged.
+    ///
+    /// Continuing to use the pointer passed in to this function is Undefined Behavior; you should replace it with the returned pointer instead.
+    ///
+    /// # Safety
+    ///
+    /// Requires the `mte` target feature; calling this on a CPU without FEAT_MTE is Undefined Behavior.
+    #[unstable(feature = "stdarch_aarch64_mte", issue = "none")]
+    unsafe fn irg(self) -> Self;
+
+    /// Returns a new pointer whose logical tag has been incremented (or decremented, for negative `i`) modulo 16, as per the `ADDG`/`SUBG` instructions. The data held in bits 60 to 63 is left unchanged.
+    ///
+    /// This is pure address arithmetic and touches no allocation-tag memory, so unlike the other methods on this trait it does not require the `mte` target feature and remains usable without `std`.
+    #[unstable(feature = "stdarch_aarch64_mte", issue = "none")]
+    fn add_tag(self, i: i8) -> Self;
+
+    /// Writes this pointer's tag into the allocation-tag storage of the 16-byte granule it points at, as per the `STG` instruction.
+    ///
+    /// # Safety
+    ///
+    /// `self` must be 16-byte aligned and point into memory backed by tag storage (i.e. memory obtained from an MTE-aware allocator). Requires the `mte` target feature.
+    #[unstable(feature = "stdarch_aarch64_mte", issue = "none")]
+    unsafe fn set_memory_tag(&self);
+
+    /// Writes this pointer's tag into the allocation-tag storage of the two consecutive 16-byte granules starting at the pointer, as per the `ST2G` instruction.
+    ///
+    /// # Safety
+    ///
+    /// `self` must be 16-byte aligned and point into memory backed by tag storage for both granules (i.e. memory obtained from an MTE-aware allocator). Requires the `mte` target feature.
+    #[unstable(feature = "stdarch_aarch64_mte", issue = "none")]
+    unsafe fn set_memory_tag_pair(&self);
+
+    /// Returns a new pointer with its tag (bits 56 to 59) replaced by the tag read back from the allocation-tag storage of the granule it points at, as per the `LDG` instruction. The data held in bits 60 to 63 is left unchanged.
+    ///
+    /// # Safety
+    ///
+    /// `self` must be 16-byte aligned and point into memory backed by tag storage (i.e. memory obtained from an MTE-aware allocator). Requires the `mte` target feature.
+    #[unstable(feature = "stdarch_aarch64_mte", issue = "none")]
+    unsafe fn load_memory_tag(&self) -> Self;
+}
+
+macro_rules! mte_ptr_impl {
+    () => {
+        #[target_feature(enable = "mte")]
+        unsafe fn irg(self) -> Self {
+            // `IRG` draws from the pseudo-random tag generator and perturbs its seed
+            // (`RGSR_EL1`), so it is neither pure nor side-effect-free: it must not be
+            // marked `options(pure, nomem)`, or the compiler may CSE/hoist repeated
+            // calls and hand back the same tag every time.
+            let addr: u64;
+            core::arch::asm!("irg {addr}, {base}", base = in(reg) self.addr() as u64, addr = out(reg) addr, options(nostack));
+            let addr = (self.addr() & !MTE_TAG_MASK) | (addr as usize & MTE_TAG_MASK);
+            self.with_addr(addr)
+        }
+
+        fn add_tag(self, i: i8) -> Self {
+            // Pure address arithmetic like `with_top_byte`: no instruction emitted, so no `mte` feature or `std` needed.
+            let tag = (self.addr() & MTE_TAG_MASK) >> 56;
+            let new_tag = (tag as i8).wrapping_add(i) as usize & 0x0f;
+            let addr = (self.addr() & !MTE_TAG_MASK) | (new_tag << 56);
+            self.with_addr(addr)
+        }
+
+        #[target_feature(enable = "mte")]
+        unsafe fn set_memory_tag(&self) {
+            core::arch::asm!("stg {addr}, [{addr}]", addr = in(reg) self.addr() as u64, options(nostack));
+        }
+
+        #[target_feature(enable = "mte")]
+        unsafe fn set_memory_tag_pair(&self) {
+            core::arch::asm!("st2g {addr}, [{addr}]", addr = in(reg) self.addr() as u64, options(nostack));
+        }
+
+        #[target_feature(enable = "mte")]
+        unsafe fn load_memory_tag(&self) -> Self {
+            let base = self.addr() as u64;
+            let tagged: u64;
+            core::arch::asm!("ldg {tagged}, [{base}]", base = in(reg) base, tagged = out(reg) tagged, options(nostack));
+            let addr = (self.addr() & !MTE_TAG_MASK) | (tagged as usize & MTE_TAG_MASK);
+            self.with_addr(addr)
+        }
+    };
+}
+
+#[unstable(feature = "stdarch_aarch64_mte", issue = "none")]
+impl<T> MTEPointer for *const T {
+    mte_ptr_impl!();
+}
+
+#[unstable(feature = "stdarch_aarch64_mte", issue = "none")]
+impl<T> MTEPointer for *mut T {
+    mte_ptr_impl!();
+}
+
+#[unstable(feature = "stdarch_aarch64_mte", issue = "none")]
+impl<T> MTEPointer for *const [T] {
+    mte_ptr_impl!();
+}
+
+#[unstable(feature = "stdarch_aarch64_mte", issue = "none")]
+impl<T> MTEPointer for *mut [T] {
+    mte_ptr_impl!();
+}
+
+/// Provides additional methods on pointers to sign and authenticate them against forgery, as per AArch64's Pointer Authentication (FEAT_PAuth) feature.
+///
+/// FEAT_PAuth reuses otherwise-unused high bits of the pointer (including, when TBI is disabled, the top byte that [`TBIPointer::with_top_byte`] and [`MTEPointer`] use; when TBI is enabled, only the bits below it), replacing them with a cryptographic signature computed from the address, a caller-supplied modifier, and a key held in hardware.
+///
+/// This trait is deliberately scoped to the A-key *data* variant (`PACDA`/`AUTDA`/`XPACD`) only, since its impls are for data pointers (`*const T`/`*mut T` and slices), not function pointers. Signing code pointers with the instruction-key variant (`PACIA`/`AUTIA`/`XPACI`) is intentionally out of scope here and would need its own trait over `fn` pointer types.
+///
+/// Signing and tagging cannot be combined: a pointer that is both signed and carries a TBI top-byte tag cannot round-trip through [`sign`](PACPointer::sign), since [`strip`](PACPointer::strip) always clears the top byte and so only ever recovers the plain canonical address, not any tag bits that were present before signing.
+#[unstable(feature = "stdarch_aarch64_pac", issue = "none")]
+pub trait PACPointer: Sized {
+    /// Returns a new pointer with a cryptographic signature of its address and `modifier` written into its high bits, as per the `PACDA` instruction.
+    ///
+    /// Continuing to use the pointer passed in to this function is Undefined Behavior; you should replace it with the returned pointer instead.
+    ///
+    /// # Safety
+    ///
+    /// Requires the `paca`/`pacg` target features; calling this on a CPU without FEAT_PAuth is Undefined Behavior.
+    #[unstable(feature = "stdarch_aarch64_pac", issue = "none")]
+    unsafe fn sign(self, modifier: u64) -> Self;
+
+    /// Returns a new pointer with its signature checked against its address and `modifier`, as per the `AUTDA` instruction. If the signature is valid, the returned pointer is the original canonical address; otherwise, an implementation defined poison value is set in the high bits such that dereferencing the pointer will fault.
+    ///
+    /// # Safety
+    ///
+    /// Requires the `paca`/`pacg` target features; calling this on a CPU without FEAT_PAuth is Undefined Behavior.
+    #[unstable(feature = "stdarch_aarch64_pac", issue = "none")]
+    unsafe fn authenticate(self, modifier: u64) -> Self;
+
+    /// Returns a new pointer with any signature in its high bits and its top byte cleared, recovering the plain canonical address without checking the signature, as per the `XPACD` instruction.
+    ///
+    /// # Safety
+    ///
+    /// Requires the `paca`/`pacg` target features; calling this on a CPU without FEAT_PAuth is Undefined Behavior.
+    #[unstable(feature = "stdarch_aarch64_pac", issue = "none")]
+    unsafe fn strip(self) -> Self;
+}
+
+macro_rules! pac_ptr_impl {
+    () => {
+        #[target_feature(enable = "paca,pacg")]
+        unsafe fn sign(self, modifier: u64) -> Self {
+            let mut addr = self.addr() as u64;
+            core::arch::asm!("pacda {addr}, {modifier}", addr = inout(reg) addr, modifier = in(reg) modifier, options(pure, nomem, nostack));
+            self.with_addr(addr as usize)
+        }
+
+        #[target_feature(enable = "paca,pacg")]
+        unsafe fn authenticate(self, modifier: u64) -> Self {
+            let mut addr = self.addr() as u64;
+            core::arch::asm!("autda {addr}, {modifier}", addr = inout(reg) addr, modifier = in(reg) modifier, options(pure, nomem, nostack));
+            self.with_addr(addr as usize)
+        }
+
+        #[target_feature(enable = "paca,pacg")]
+        unsafe fn strip(self) -> Self {
+            let mut addr = self.addr() as u64;
+            core::arch::asm!("xpacd {addr}", addr = inout(reg) addr, options(pure, nomem, nostack));
+            // With TBI enabled, `XPACD` keeps the top byte as it was, so clear it here too.
+            self.with_addr(addr as usize & 0x00ffffffffffffff)
+        }
+    };
+}
+
+#[unstable(feature = "stdarch_aarch64_pac", issue = "none")]
+impl<T> PACPointer for *const T {
+    pac_ptr_impl!();
+}
+
+#[unstable(feature = "stdarch_aarch64_pac", issue = "none")]
+impl<T> PACPointer for *mut T {
+    pac_ptr_impl!();
+}
+
+#[unstable(feature = "stdarch_aarch64_pac", issue = "none")]
+impl<T> PACPointer for *const [T] {
+    pac_ptr_impl!();
+}
+
+#[unstable(feature = "stdarch_aarch64_pac", issue = "none")]
+impl<T> PACPointer for *mut [T] {
+    pac_ptr_impl!();
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -91,4 +330,70 @@ mod test {
         unsafe { (*address)[0] = 25 };
         assert_eq!(unsafe { *address }, [25, 255, 65535, 0xffffffff]);
     }
+
+    #[test]
+    fn mte_add_tag_wraps_modulo_16() {
+        let value: u32 = 10;
+        let address = (&value as *const u32).with_top_byte(0xff);
+        let address = address.add_tag(1);
+        assert_eq!(address.top_byte() & 0x0f, 0x00);
+        // The TBI-style data in the high nibble must survive the tag update.
+        assert_eq!(address.top_byte() & 0xf0, 0xf0);
+    }
+
+    #[test]
+    fn mte_add_tag_preserves_tbi_data() {
+        let value: u32 = 10;
+        let address = (&value as *const u32).with_top_byte(0x0f);
+        let address = address.add_tag(-1);
+        assert_eq!(address.top_byte() & 0x0f, 0x0e);
+        assert_eq!(address.top_byte() & 0xf0, 0x00);
+    }
+
+    #[test]
+    #[cfg(target_feature = "paca")]
+    fn pac_sign_authenticate_roundtrip() {
+        let value: u32 = 10;
+        let address = &value as *const u32;
+        let signed = unsafe { address.sign(0x1234) };
+        let authenticated = unsafe { signed.authenticate(0x1234) };
+        assert_eq!(authenticated, address);
+        assert_eq!(unsafe { *authenticated }, 10);
+    }
+
+    #[test]
+    #[cfg(target_feature = "paca")]
+    fn pac_sign_then_tbi_tag_cannot_round_trip() {
+        let value: u32 = 10;
+        let address = &value as *const u32;
+        // `strip` clears the top byte along with the signature, so it recovers the
+        // canonical address, not the tagged one.
+        let tagged = address.with_top_byte(0x80);
+        let signed = unsafe { tagged.sign(0x1234) };
+        let stripped = unsafe { signed.strip() };
+        assert_eq!(stripped, address);
+        assert_ne!(stripped, tagged);
+    }
+
+    #[test]
+    fn canonical_clears_top_byte() {
+        let value: u32 = 10;
+        let address = &value as *const u32;
+        let tagged = address.with_top_byte(0x80);
+        assert_eq!(tagged.canonical(), address);
+        assert_eq!(tagged.canonical().top_byte(), 0);
+    }
+
+    #[test]
+    fn same_object_ignores_tag_bits() {
+        let value: u32 = 10;
+        let other_value: u32 = 10;
+        let address = &value as *const u32;
+        let tagged = address.with_top_byte(0x80);
+        assert!(address.same_object(&tagged));
+        assert_ne!(address, tagged);
+
+        let other_address = &other_value as *const u32;
+        assert!(!address.same_object(&other_address));
+    }
 }